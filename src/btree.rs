@@ -0,0 +1,353 @@
+use std::cmp::Ordering;
+
+use crate::Tree;
+
+/// Minimum degree of the tree: every non-root node holds between
+/// `B - 1` and `2B - 1` keys. Kept small enough to exercise splits and
+/// merges in tests, but big enough that each node spans several cache
+/// lines' worth of keys instead of the single key per allocation that
+/// `BinaryTreeNode` pays for.
+const B: usize = 6;
+const MAX_KEYS: usize = 2 * B - 1;
+
+struct Node<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    children: Vec<Node<K, V>>,
+}
+
+impl<K: Ord, V> Node<K, V> {
+    fn new_leaf() -> Node<K, V> {
+        Node {
+            keys: Vec::new(),
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        match self.keys.binary_search(key) {
+            Ok(i) => Some(&self.values[i]),
+            Err(_) if self.is_leaf() => None,
+            Err(i) => self.children[i].get(key),
+        }
+    }
+
+    // Split the full child at `i` (it must hold `MAX_KEYS` keys) into two
+    // nodes of `B - 1` keys each, promoting its median key/value into
+    // `self` at index `i` with the new sibling at `i + 1`.
+    fn split_child(&mut self, i: usize) {
+        let mid = B - 1;
+        let (median_key, median_value, sibling) = {
+            let child = &mut self.children[i];
+            let sibling_keys = child.keys.split_off(mid + 1);
+            let sibling_values = child.values.split_off(mid + 1);
+            let median_key = child.keys.pop().unwrap();
+            let median_value = child.values.pop().unwrap();
+            let sibling_children = if child.children.is_empty() {
+                Vec::new()
+            } else {
+                child.children.split_off(mid + 1)
+            };
+            (
+                median_key,
+                median_value,
+                Node {
+                    keys: sibling_keys,
+                    values: sibling_values,
+                    children: sibling_children,
+                },
+            )
+        };
+        self.keys.insert(i, median_key);
+        self.values.insert(i, median_value);
+        self.children.insert(i + 1, sibling);
+    }
+
+    fn insert_non_full(&mut self, key: K, value: V) {
+        match self.keys.binary_search(&key) {
+            Ok(i) => self.values[i] = value,
+            Err(mut i) => {
+                if self.is_leaf() {
+                    self.keys.insert(i, key);
+                    self.values.insert(i, value);
+                } else {
+                    if self.children[i].keys.len() == MAX_KEYS {
+                        self.split_child(i);
+                        match key.cmp(&self.keys[i]) {
+                            Ordering::Greater => i += 1,
+                            Ordering::Equal => {
+                                self.values[i] = value;
+                                return;
+                            }
+                            Ordering::Less => {}
+                        }
+                    }
+                    self.children[i].insert_non_full(key, value);
+                }
+            }
+        }
+    }
+
+    // Merge `children[i + 1]` and the separator at `keys[i]` into
+    // `children[i]`, leaving the merged node with `2B - 1` keys and
+    // removing the now-redundant separator and right sibling from `self`.
+    fn merge_children(&mut self, i: usize) {
+        let right = self.children.remove(i + 1);
+        let sep_key = self.keys.remove(i);
+        let sep_value = self.values.remove(i);
+
+        let left = &mut self.children[i];
+        left.keys.push(sep_key);
+        left.values.push(sep_value);
+        left.keys.extend(right.keys);
+        left.values.extend(right.values);
+        left.children.extend(right.children);
+    }
+
+    fn rotate_from_left(&mut self, i: usize) {
+        let moved_key = self.children[i - 1].keys.pop().unwrap();
+        let moved_value = self.children[i - 1].values.pop().unwrap();
+        let moved_child = self.children[i - 1].children.pop();
+
+        let sep_key = std::mem::replace(&mut self.keys[i - 1], moved_key);
+        let sep_value = std::mem::replace(&mut self.values[i - 1], moved_value);
+
+        self.children[i].keys.insert(0, sep_key);
+        self.children[i].values.insert(0, sep_value);
+        if let Some(child) = moved_child {
+            self.children[i].children.insert(0, child);
+        }
+    }
+
+    fn rotate_from_right(&mut self, i: usize) {
+        let moved_key = self.children[i + 1].keys.remove(0);
+        let moved_value = self.children[i + 1].values.remove(0);
+        let moved_child = if self.children[i + 1].children.is_empty() {
+            None
+        } else {
+            Some(self.children[i + 1].children.remove(0))
+        };
+
+        let sep_key = std::mem::replace(&mut self.keys[i], moved_key);
+        let sep_value = std::mem::replace(&mut self.values[i], moved_value);
+
+        self.children[i].keys.push(sep_key);
+        self.children[i].values.push(sep_value);
+        if let Some(child) = moved_child {
+            self.children[i].children.push(child);
+        }
+    }
+
+    // Ensure `children[i]` holds at least `B` keys before we descend into
+    // it, by borrowing a key from a sibling that can spare one, or
+    // merging with a sibling otherwise. Returns the index of the
+    // now-fixed child, since a merge can shift it down by one.
+    fn ensure_child_has_min_keys(&mut self, i: usize) -> usize {
+        if self.children[i].keys.len() >= B {
+            return i;
+        }
+        if i > 0 && self.children[i - 1].keys.len() >= B {
+            self.rotate_from_left(i);
+            return i;
+        }
+        if i + 1 < self.children.len() && self.children[i + 1].keys.len() >= B {
+            self.rotate_from_right(i);
+            return i;
+        }
+        if i + 1 < self.children.len() {
+            self.merge_children(i);
+            i
+        } else {
+            self.merge_children(i - 1);
+            i - 1
+        }
+    }
+
+    // Remove and return the key/value at `keys[i]`, rebalancing the
+    // affected subtree (via predecessor/successor replacement or a
+    // merge) so the B-tree invariants still hold afterward.
+    fn remove_at(&mut self, i: usize) -> (K, V) {
+        if self.is_leaf() {
+            (self.keys.remove(i), self.values.remove(i))
+        } else if self.children[i].keys.len() >= B {
+            let (pred_key, pred_value) = self.children[i].remove_max();
+            let old_key = std::mem::replace(&mut self.keys[i], pred_key);
+            let old_value = std::mem::replace(&mut self.values[i], pred_value);
+            (old_key, old_value)
+        } else if self.children[i + 1].keys.len() >= B {
+            let (succ_key, succ_value) = self.children[i + 1].remove_min();
+            let old_key = std::mem::replace(&mut self.keys[i], succ_key);
+            let old_value = std::mem::replace(&mut self.values[i], succ_value);
+            (old_key, old_value)
+        } else {
+            let left_len = self.children[i].keys.len();
+            self.merge_children(i);
+            self.children[i].remove_at(left_len)
+        }
+    }
+
+    fn remove_min(&mut self) -> (K, V) {
+        if self.is_leaf() {
+            (self.keys.remove(0), self.values.remove(0))
+        } else {
+            let i = self.ensure_child_has_min_keys(0);
+            self.children[i].remove_min()
+        }
+    }
+
+    fn remove_max(&mut self) -> (K, V) {
+        if self.is_leaf() {
+            let last = self.keys.len() - 1;
+            (self.keys.remove(last), self.values.remove(last))
+        } else {
+            let last = self.children.len() - 1;
+            let i = self.ensure_child_has_min_keys(last);
+            self.children[i].remove_max()
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        match self.keys.binary_search(key) {
+            Ok(i) => Some(self.remove_at(i).1),
+            Err(i) => {
+                if self.is_leaf() {
+                    None
+                } else {
+                    let i = self.ensure_child_has_min_keys(i);
+                    self.children[i].remove(key)
+                }
+            }
+        }
+    }
+}
+
+/// A cache-efficient B-tree implementation of [`Tree`]: each node holds
+/// up to `2B - 1` keys in contiguous arrays rather than the single
+/// `Box`-per-key layout of [`BinaryTree`](crate::BinaryTree), so lookups
+/// do fewer, wider memory accesses as the tree grows past a few thousand
+/// entries.
+pub struct BTree<K, V> {
+    root: Box<Node<K, V>>,
+}
+
+impl<K: Ord, V> BTree<K, V> {
+    pub fn new() -> BTree<K, V> {
+        BTree {
+            root: Box::new(Node::new_leaf()),
+        }
+    }
+}
+
+impl<K: Ord, V> Default for BTree<K, V> {
+    fn default() -> Self {
+        BTree::new()
+    }
+}
+
+impl<K: Ord, V> Tree<K, V> for BTree<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        self.root.get(key)
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        if self.root.keys.len() == MAX_KEYS {
+            let old_root = std::mem::replace(&mut *self.root, Node::new_leaf());
+            let mut new_root = Node::new_leaf();
+            new_root.children.push(old_root);
+            new_root.split_child(0);
+            *self.root = new_root;
+        }
+        self.root.insert_non_full(key, value);
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let removed = self.root.remove(key);
+        if self.root.keys.is_empty() && !self.root.is_leaf() {
+            let only_child = self.root.children.pop().unwrap();
+            *self.root = only_child;
+        }
+        removed
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.keys.is_empty() && self.root.is_leaf()
+    }
+
+    fn clear(&mut self) {
+        *self.root = Node::new_leaf();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BTree;
+    use crate::Tree;
+
+    #[test]
+    fn basic_put_get() {
+        let mut tree: BTree<i32, &str> = BTree::new();
+        tree.put(2, "two");
+        tree.put(1, "one");
+        tree.put(3, "three");
+
+        assert_eq!(Some(&"one"), tree.get(&1));
+        assert_eq!(Some(&"two"), tree.get(&2));
+        assert_eq!(Some(&"three"), tree.get(&3));
+        assert_eq!(None, tree.get(&4));
+    }
+
+    #[test]
+    fn put_overwrites_existing_key() {
+        let mut tree: BTree<i32, &str> = BTree::new();
+        tree.put(1, "one");
+        tree.put(1, "uno");
+        assert_eq!(Some(&"uno"), tree.get(&1));
+    }
+
+    #[test]
+    fn splits_and_finds_all_keys_across_many_inserts() {
+        let mut tree: BTree<i32, i32> = BTree::new();
+        for i in 0..10_000 {
+            tree.put(i, i * 2);
+        }
+        for i in 0..10_000 {
+            assert_eq!(Some(&(i * 2)), tree.get(&i));
+        }
+    }
+
+    #[test]
+    fn remove_triggers_merges_and_rotations() {
+        let mut tree: BTree<i32, i32> = BTree::new();
+        for i in 0..500 {
+            tree.put(i, i);
+        }
+        for i in 0..500 {
+            assert_eq!(Some(i), tree.remove(&i));
+            assert_eq!(None, tree.get(&i));
+        }
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn remove_missing_key_is_none() {
+        let mut tree: BTree<i32, i32> = BTree::new();
+        tree.put(1, 1);
+        assert_eq!(None, tree.remove(&2));
+    }
+
+    #[test]
+    fn clear_empties_tree() {
+        let mut tree: BTree<i32, i32> = BTree::new();
+        for i in 0..50 {
+            tree.put(i, i);
+        }
+        tree.clear();
+        assert!(tree.is_empty());
+        assert_eq!(None, tree.get(&1));
+    }
+}
@@ -1,3 +1,15 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+mod btree;
+mod merkle;
+mod red_black;
+
+pub use btree::BTree;
+pub use merkle::{verify, MerkleTree, Proof};
+pub use red_black::RedBlackTree;
+
 pub trait Tree<K,V> {
     fn get(&self, key: &K) -> Option<&V>;
     fn put(&mut self, key: K, value: V);
@@ -14,7 +26,7 @@ pub struct BinaryTreeNode<K,V> {
     right: Option<Box<BinaryTreeNode<K,V>>>,
 }
 
-impl <K: Ord + Clone, V: Clone> BinaryTreeNode<K, V> {
+impl <K: Ord, V> BinaryTreeNode<K, V> {
     pub fn new(key: K, value: V) -> BinaryTreeNode<K, V> {
         BinaryTreeNode {
             key,
@@ -25,64 +37,51 @@ impl <K: Ord + Clone, V: Clone> BinaryTreeNode<K, V> {
     }
 
     fn remove_descendent(&mut self, key: &K) -> Option<V> {
-        if self.left.is_some() && self.left.as_ref().unwrap().key == *key {
-            return self.remove_left();
-        }
-
-        if self.left.is_some() && *key < self.key {
-            return self.left.as_mut().unwrap().remove_descendent(key);
-        }
-
-        if self.right.is_some() && self.right.as_ref().unwrap().key == *key {
-            return self.remove_right();
-        }
-
-        if self.right.is_some() && *key < self.key {
-            return self.right.as_mut().unwrap().remove_descendent(key);
+        match key.cmp(&self.key) {
+            Ordering::Less => match self.left.as_deref() {
+                Some(left) if left.key == *key => self.remove_left(),
+                Some(_) => self.left.as_mut().unwrap().remove_descendent(key),
+                None => None,
+            },
+            Ordering::Greater => match self.right.as_deref() {
+                Some(right) if right.key == *key => self.remove_right(),
+                Some(_) => self.right.as_mut().unwrap().remove_descendent(key),
+                None => None,
+            },
+            Ordering::Equal => None,
         }
-
-        None
     }
 
     fn remove_left(&mut self) -> Option<V> {
-        let mut removed_left = self.left.take().unwrap();
-        self.left = removed_left.take_subtree();
-        Some(removed_left.value)
+        let removed_left = self.left.take().unwrap();
+        let (replacement, value) = removed_left.delete();
+        self.left = replacement;
+        Some(value)
     }
 
     fn remove_right(&mut self) -> Option<V> {
-        let mut removed_right = self.right.take().unwrap();
-        self.right = removed_right.take_subtree();
-        Some(removed_right.value)
+        let removed_right = self.right.take().unwrap();
+        let (replacement, value) = removed_right.delete();
+        self.right = replacement;
+        Some(value)
     }
 
-    fn take_subtree(&mut self) -> Option<Box<BinaryTreeNode<K,V>>> {
-        match (self.left.as_ref(), self.right.as_ref()) {
+    // Consume this node, splicing its in-order successor (the leftmost
+    // node of its right subtree) into its place when it has two
+    // children, by moving nodes rather than cloning keys/values.
+    fn delete(mut self) -> (Option<Box<BinaryTreeNode<K,V>>>, V) {
+        let replacement = match (self.left.take(), self.right.take()) {
             (None, None) => None,
-            (Some(_), None) => self.left.take(),
-            (None, Some(_)) => self.right.take(),
-            (Some(_), Some(_)) => Some(self.take_right_min_subtree())
-        }
-    }
-
-    fn take_right_min_subtree(&mut self) -> Box<BinaryTreeNode<K,V>> {
-        let (min_key, min_value) = self.right.as_ref().unwrap().min_key_value();
-        let copied_key = min_key.clone();
-        let mut new_node = BinaryTreeNode{
-            key: min_key,
-            value: min_value,
-            left: self.left.take(),
-            right: self.right.take()
+            (Some(left), None) => Some(left),
+            (None, Some(right)) => Some(right),
+            (Some(left), Some(right)) => {
+                let (mut successor, remaining_right) = take_leftmost(right);
+                successor.left = Some(left);
+                successor.right = remaining_right;
+                Some(successor)
+            }
         };
-        new_node.remove_descendent(&copied_key);
-        Box::new(new_node)
-    }
-
-    fn min_key_value(&self) -> (K, V) {
-        match self.left {
-            None => (self.key.clone(), self.value.clone()),
-            Some(ref left) => left.min_key_value(),
-        }
+        (replacement, self.value)
     }
 
     pub fn find(&self, key: &K) -> Option<&V> {
@@ -101,45 +100,316 @@ impl <K: Ord + Clone, V: Clone> BinaryTreeNode<K, V> {
         }
     }
 
-    pub fn append(&mut self, node: BinaryTreeNode<K,V>) {
+    // Returns `true` if `node` was inserted as a new descendant, or `false`
+    // if it overwrote the value of an existing key.
+    pub fn append(&mut self, node: BinaryTreeNode<K,V>) -> bool {
         if node.key < self.key {
-            self.insert_left(node);
+            self.insert_left(node)
         } else if node.key == self.key {
             self.value = node.value;
+            false
         } else{
-            self.insert_right(node);
+            self.insert_right(node)
         }
     }
 
-    fn insert_left(&mut self, node: BinaryTreeNode<K,V>) {
+    fn insert_left(&mut self, node: BinaryTreeNode<K,V>) -> bool {
         match self.left {
-            None => self.left = Some(Box::new(node)),
-            Some(ref mut left) => left.append(node)
+            None => {
+                self.left = Some(Box::new(node));
+                true
+            }
+            Some(ref mut left) => left.append(node),
         }
     }
 
-    fn insert_right(&mut self, node: BinaryTreeNode<K,V>) {
+    fn insert_right(&mut self, node: BinaryTreeNode<K,V>) -> bool {
         match self.right {
-            None => self.right = Some(Box::new(node)),
-            Some(ref mut right) => right.append(node)
+            None => {
+                self.right = Some(Box::new(node));
+                true
+            }
+            Some(ref mut right) => right.append(node),
+        }
+    }
+}
+
+fn node_height<K, V>(node: Option<&BinaryTreeNode<K, V>>) -> usize {
+    match node {
+        None => 0,
+        Some(n) => 1 + node_height(n.left.as_deref()).max(node_height(n.right.as_deref())),
+    }
+}
+
+type Link<K, V> = Option<Box<BinaryTreeNode<K, V>>>;
+
+// Descend the left spine of `node`, returning the leftmost node (with its
+// left link already cleared) and whatever is left of the subtree once it
+// is removed.
+fn take_leftmost<K,V>(mut node: Box<BinaryTreeNode<K,V>>) -> (Box<BinaryTreeNode<K,V>>, Link<K, V>) {
+    match node.left.take() {
+        None => {
+            let right = node.right.take();
+            (node, right)
+        }
+        Some(left) => {
+            let (leftmost, remaining_left) = take_leftmost(left);
+            node.left = remaining_left;
+            (leftmost, Some(node))
         }
     }
 }
 
 pub struct BinaryTree<K,V> {
     root: Option<BinaryTreeNode<K,V>>,
+    len: usize,
 }
 
 
-impl <K: Ord + Clone, V: Clone> BinaryTree<K, V> {
+impl <K: Ord, V> BinaryTree<K, V> {
     pub fn new() -> BinaryTree<K,V> {
         BinaryTree{
             root: None,
+            len: 0,
         }
     }
+
+    /// Number of entries currently stored in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the tree holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Height of the tree, i.e. the number of nodes on the longest
+    /// root-to-leaf path. `0` for an empty tree.
+    pub fn height(&self) -> usize {
+        node_height(self.root.as_ref())
+    }
+
+    /// Iterate over `(&K, &V)` pairs in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.root.as_ref())
+    }
+
+    /// Iterate over `(&K, &mut V)` pairs in ascending key order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(self.root.as_mut())
+    }
+
+    /// Iterate over keys in ascending order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Iterate over values in ascending key order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Iterate over `(&K, &V)` pairs whose key falls within `range`, in
+    /// ascending order, e.g. `tree.range("a".."m")` or `tree.range(..="z")`.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V, R> {
+        let mut stack = Vec::new();
+        push_range_left(self.root.as_ref(), &range, &mut stack);
+        Range { range, stack }
+    }
+}
+
+impl<K: Ord, V> Default for BinaryTree<K, V> {
+    fn default() -> Self {
+        BinaryTree::new()
+    }
+}
+
+impl<K: Ord, V> IntoIterator for BinaryTree<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter::new(self.root)
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a BinaryTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// Lazy in-order iterator over `(&K, &V)` pairs, driven by an explicit
+/// stack of the left spine rather than collecting into a `Vec` up front.
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a BinaryTreeNode<K, V>>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn new(root: Option<&'a BinaryTreeNode<K, V>>) -> Iter<'a, K, V> {
+        let mut stack = Vec::new();
+        push_left_spine(root, &mut stack);
+        Iter { stack }
+    }
+}
+
+fn push_left_spine<'a, K, V>(
+    mut node: Option<&'a BinaryTreeNode<K, V>>,
+    stack: &mut Vec<&'a BinaryTreeNode<K, V>>,
+) {
+    while let Some(n) = node {
+        node = n.left.as_deref();
+        stack.push(n);
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        push_left_spine(node.right.as_deref(), &mut self.stack);
+        Some((&node.key, &node.value))
+    }
+}
+
+/// Lazy in-order iterator over `(&K, &mut V)` pairs, driven by an
+/// explicit stack of the left spine.
+///
+/// Unlike [`Iter`], each stack entry holds the node's key/value split from
+/// its right child rather than the whole node: reborrowing `n.left` and
+/// `n.right` off of the same `&mut BinaryTreeNode` to push both the next
+/// left node and the pending right subtree does not borrow-check, since
+/// the two reborrows would alias through `n` once it is stored. Splitting
+/// the fields up front avoids that.
+type IterMutEntry<'a, K, V> = (&'a K, &'a mut V, Option<&'a mut BinaryTreeNode<K, V>>);
+
+pub struct IterMut<'a, K, V> {
+    stack: Vec<IterMutEntry<'a, K, V>>,
+}
+
+impl<'a, K, V> IterMut<'a, K, V> {
+    fn new(root: Option<&'a mut BinaryTreeNode<K, V>>) -> IterMut<'a, K, V> {
+        let mut stack = Vec::new();
+        push_left_spine_mut(root, &mut stack);
+        IterMut { stack }
+    }
+}
+
+fn push_left_spine_mut<'a, K, V>(
+    mut node: Option<&'a mut BinaryTreeNode<K, V>>,
+    stack: &mut Vec<IterMutEntry<'a, K, V>>,
+) {
+    while let Some(n) = node {
+        let BinaryTreeNode { key, value, left, right } = n;
+        node = left.as_deref_mut();
+        stack.push((key, value, right.as_deref_mut()));
+    }
 }
 
-impl<K: Ord + Clone,V: Clone> Tree<K,V> for BinaryTree<K,V> {
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value, right) = self.stack.pop()?;
+        push_left_spine_mut(right, &mut self.stack);
+        Some((key, value))
+    }
+}
+
+/// Lazy in-order iterator over owned `(K, V)` pairs, consuming the tree.
+pub struct IntoIter<K, V> {
+    stack: Vec<BinaryTreeNode<K, V>>,
+}
+
+impl<K, V> IntoIter<K, V> {
+    fn new(root: Option<BinaryTreeNode<K, V>>) -> IntoIter<K, V> {
+        let mut stack = Vec::new();
+        push_left_spine_owned(root, &mut stack);
+        IntoIter { stack }
+    }
+}
+
+fn push_left_spine_owned<K, V>(
+    mut node: Option<BinaryTreeNode<K, V>>,
+    stack: &mut Vec<BinaryTreeNode<K, V>>,
+) {
+    while let Some(mut n) = node {
+        node = n.left.take().map(|boxed| *boxed);
+        stack.push(n);
+    }
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut node = self.stack.pop()?;
+        push_left_spine_owned(node.right.take().map(|boxed| *boxed), &mut self.stack);
+        Some((node.key, node.value))
+    }
+}
+
+fn below_start<K: Ord, R: RangeBounds<K>>(key: &K, range: &R) -> bool {
+    match range.start_bound() {
+        Bound::Included(start) => key < start,
+        Bound::Excluded(start) => key <= start,
+        Bound::Unbounded => false,
+    }
+}
+
+fn above_end<K: Ord, R: RangeBounds<K>>(key: &K, range: &R) -> bool {
+    match range.end_bound() {
+        Bound::Included(end) => key > end,
+        Bound::Excluded(end) => key >= end,
+        Bound::Unbounded => false,
+    }
+}
+
+// Descend toward the smallest in-range node, pushing every node known to
+// be in range onto `stack` along the way. A node below the lower bound
+// has its whole left subtree below the bound too, so we skip left and
+// continue right; a node above the upper bound has its whole right
+// subtree above the bound too, so we skip right and continue left.
+fn push_range_left<'a, K: Ord, V, R: RangeBounds<K>>(
+    mut node: Option<&'a BinaryTreeNode<K, V>>,
+    range: &R,
+    stack: &mut Vec<&'a BinaryTreeNode<K, V>>,
+) {
+    while let Some(n) = node {
+        if below_start(&n.key, range) {
+            node = n.right.as_deref();
+        } else if above_end(&n.key, range) {
+            node = n.left.as_deref();
+        } else {
+            stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+/// Lazy in-order iterator over `(&K, &V)` pairs whose key falls within a
+/// bound, produced by [`BinaryTree::range`].
+pub struct Range<'a, K, V, R> {
+    range: R,
+    stack: Vec<&'a BinaryTreeNode<K, V>>,
+}
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> Iterator for Range<'a, K, V, R> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        push_range_left(node.right.as_deref(), &self.range, &mut self.stack);
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K: Ord, V> Tree<K,V> for BinaryTree<K,V> {
     fn get(&self, key: &K) -> Option<&V> {
         match self.root {
             None => None,
@@ -149,41 +419,87 @@ impl<K: Ord + Clone,V: Clone> Tree<K,V> for BinaryTree<K,V> {
 
     fn put(&mut self, key: K, value: V) {
         match self.root {
-            None => self.root = Some(BinaryTreeNode::new(key, value)),
-            Some(ref mut node) => node.append(BinaryTreeNode::new(key, value))
+            None => {
+                self.root = Some(BinaryTreeNode::new(key, value));
+                self.len += 1;
+            }
+            Some(ref mut node) => {
+                if node.append(BinaryTreeNode::new(key, value)) {
+                    self.len += 1;
+                }
+            }
         }
     }
 
     fn remove(&mut self, key: &K) -> Option<V> {
-        if self.root.is_none() {
-            return None;
-        }
-
-        if self.root.as_ref().unwrap().key != *key {
-            return self.root.as_mut().unwrap().remove_descendent(key);
+        let mut root = self.root.take()?;
+        let value = if root.key == *key {
+            let (replacement, value) = root.delete();
+            self.root = replacement.map(|node| *node);
+            Some(value)
+        } else {
+            let value = root.remove_descendent(key);
+            self.root = Some(root);
+            value
+        };
+        if value.is_some() {
+            self.len -= 1;
         }
+        value
+    }
 
-        let removed_value = self.root.as_ref().unwrap().value.clone();
-        self.root = self.root.as_mut().unwrap().take_subtree().map(|r| *r);
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
 
-        Some(removed_value)
+    fn clear(&mut self){
+        self.root = None;
+        self.len = 0;
     }
+}
 
-    fn is_empty(&self) -> bool {
-        match self.root {
-            None => true,
-            Some(_) => false,
+// Render a node's subtree as ASCII art, using `prefix` as the indentation
+// already printed for this depth and `is_last` to pick the branch
+// connector so sibling lines stay aligned.
+fn fmt_node<K: fmt::Display, V: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    node: &BinaryTreeNode<K, V>,
+    prefix: &str,
+    is_last: bool,
+) -> fmt::Result {
+    writeln!(
+        f,
+        "{}{}{}: {}",
+        prefix,
+        if is_last { "\\-- " } else { "/-- " },
+        node.key,
+        node.value
+    )?;
+    let child_prefix = format!("{}{}", prefix, if is_last { "    " } else { "|   " });
+    match (&node.left, &node.right) {
+        (None, None) => Ok(()),
+        (Some(left), None) => fmt_node(f, left, &child_prefix, true),
+        (None, Some(right)) => fmt_node(f, right, &child_prefix, true),
+        (Some(left), Some(right)) => {
+            fmt_node(f, left, &child_prefix, false)?;
+            fmt_node(f, right, &child_prefix, true)
         }
     }
+}
 
-    fn clear(&mut self){
-        self.root = None
+impl<K: fmt::Display, V: fmt::Display> fmt::Display for BinaryTree<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.root {
+            None => write!(f, "(empty)"),
+            Some(ref root) => fmt_node(f, root, "", true),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Tree, BinaryTree};
+    use std::ops::Bound;
 
     #[test]
     fn basic_put_get() {
@@ -250,4 +566,151 @@ mod tests {
         assert_eq!(&"doot".to_string(), bin_tree.get(&"4".to_string()).unwrap());
         assert_eq!(&"uber".to_string(), bin_tree.get(&"9".to_string()).unwrap());
     }
+
+    #[test]
+    fn remove_does_not_require_clone() {
+        // BinaryTree<K,V> has no Clone bound, so a non-Clone value type
+        // must still work with put/remove.
+        struct NotClone(i32);
+
+        let mut bin_tree: BinaryTree<i32, NotClone> = BinaryTree::new();
+        bin_tree.put(2, NotClone(20));
+        bin_tree.put(1, NotClone(10));
+        bin_tree.put(3, NotClone(30));
+
+        assert_eq!(20, bin_tree.remove(&2).unwrap().0);
+        assert_eq!(10, bin_tree.get(&1).unwrap().0);
+        assert_eq!(30, bin_tree.get(&3).unwrap().0);
+    }
+
+    #[test]
+    fn iter_yields_ascending_order() {
+        let mut bin_tree: BinaryTree<i32, &str> = BinaryTree::new();
+        bin_tree.put(2, "two");
+        bin_tree.put(1, "one");
+        bin_tree.put(3, "three");
+
+        let entries: Vec<(i32, &str)> = bin_tree.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(vec![(1, "one"), (2, "two"), (3, "three")], entries);
+        assert_eq!(vec![1, 2, 3], bin_tree.keys().copied().collect::<Vec<_>>());
+        assert_eq!(
+            vec!["one", "two", "three"],
+            bin_tree.values().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn iter_mut_updates_values_in_place() {
+        let mut bin_tree: BinaryTree<i32, i32> = BinaryTree::new();
+        bin_tree.put(2, 20);
+        bin_tree.put(1, 10);
+        bin_tree.put(3, 30);
+
+        for (_, value) in bin_tree.iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(vec![11, 21, 31], bin_tree.values().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn into_iter_consumes_tree_in_order() {
+        let mut bin_tree: BinaryTree<i32, &str> = BinaryTree::new();
+        bin_tree.put(2, "two");
+        bin_tree.put(1, "one");
+        bin_tree.put(3, "three");
+
+        let entries: Vec<(i32, &str)> = bin_tree.into_iter().collect();
+        assert_eq!(vec![(1, "one"), (2, "two"), (3, "three")], entries);
+    }
+
+    fn numbered_tree() -> BinaryTree<i32, i32> {
+        let mut bin_tree = BinaryTree::new();
+        for key in [5, 2, 8, 1, 3, 7, 9] {
+            bin_tree.put(key, key * 10);
+        }
+        bin_tree
+    }
+
+    #[test]
+    fn range_inclusive_bounds() {
+        let bin_tree = numbered_tree();
+        let keys: Vec<i32> = bin_tree.range(3..=7).map(|(k, _)| *k).collect();
+        assert_eq!(vec![3, 5, 7], keys);
+    }
+
+    #[test]
+    fn range_unbounded_end() {
+        let bin_tree = numbered_tree();
+        let keys: Vec<i32> = bin_tree.range(7..).map(|(k, _)| *k).collect();
+        assert_eq!(vec![7, 8, 9], keys);
+    }
+
+    #[test]
+    fn range_unbounded_start() {
+        let bin_tree = numbered_tree();
+        let keys: Vec<i32> = bin_tree.range(..=3).map(|(k, _)| *k).collect();
+        assert_eq!(vec![1, 2, 3], keys);
+    }
+
+    #[test]
+    fn range_empty_when_bounds_reversed() {
+        let bin_tree = numbered_tree();
+        // `7..3` would trip clippy::reversed_empty_ranges as a literal;
+        // build the same reversed bounds through `Bound` instead.
+        let keys: Vec<i32> = bin_tree
+            .range((Bound::Included(7), Bound::Excluded(3)))
+            .map(|(k, _)| *k)
+            .collect();
+        assert!(keys.is_empty());
+    }
+
+    #[test]
+    fn len_tracks_puts_overwrites_and_removes() {
+        let mut bin_tree: BinaryTree<i32, i32> = BinaryTree::new();
+        assert_eq!(0, bin_tree.len());
+
+        bin_tree.put(1, 10);
+        bin_tree.put(2, 20);
+        bin_tree.put(3, 30);
+        assert_eq!(3, bin_tree.len());
+
+        bin_tree.put(2, 200);
+        assert_eq!(3, bin_tree.len());
+
+        bin_tree.remove(&2);
+        assert_eq!(2, bin_tree.len());
+
+        bin_tree.clear();
+        assert_eq!(0, bin_tree.len());
+    }
+
+    #[test]
+    fn height_reflects_tree_shape() {
+        let mut bin_tree: BinaryTree<i32, i32> = BinaryTree::new();
+        assert_eq!(0, bin_tree.height());
+
+        bin_tree.put(2, 20);
+        assert_eq!(1, bin_tree.height());
+
+        bin_tree.put(1, 10);
+        bin_tree.put(3, 30);
+        assert_eq!(2, bin_tree.height());
+    }
+
+    #[test]
+    fn display_renders_ascii_tree() {
+        let bin_tree = numbered_tree();
+        let rendered = bin_tree.to_string();
+
+        assert!(rendered.contains("\\-- 5: 50"));
+        assert!(rendered.contains("/-- 2: 20"));
+        assert_eq!(bin_tree.len(), rendered.lines().count());
+    }
+
+    #[test]
+    fn display_empty_tree() {
+        let bin_tree: BinaryTree<i32, i32> = BinaryTree::new();
+        assert_eq!("(empty)", bin_tree.to_string());
+    }
 }
@@ -0,0 +1,358 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::Tree;
+
+const EMPTY_HASH: [u8; 32] = [0u8; 32];
+
+// Hash an arbitrary `Hash` value out to 32 bytes by running it through
+// `DefaultHasher` four times with a distinct seed each time. There's no
+// hashing crate in this workspace, so this stands in for a real
+// cryptographic hash (e.g. SHA-256) while keeping the same `[u8; 32]`
+// shape callers would get from one.
+fn hash32<T: Hash + ?Sized>(value: &T) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (seed, chunk) in out.chunks_mut(8).enumerate() {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        value.hash(&mut hasher);
+        chunk.copy_from_slice(&hasher.finish().to_be_bytes());
+    }
+    out
+}
+
+fn combine(left: &[u8; 32], key_hash: &[u8; 32], value_hash: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(4 * 32);
+    bytes.extend_from_slice(left);
+    bytes.extend_from_slice(key_hash);
+    bytes.extend_from_slice(value_hash);
+    bytes.extend_from_slice(right);
+    hash32(bytes.as_slice())
+}
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    hash: [u8; 32],
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+fn link_hash<K, V>(link: &Link<K, V>) -> [u8; 32] {
+    match link {
+        Some(node) => node.hash,
+        None => EMPTY_HASH,
+    }
+}
+
+impl<K: Ord + Hash, V: Hash> Node<K, V> {
+    fn new(key: K, value: V) -> Node<K, V> {
+        let hash = combine(&EMPTY_HASH, &hash32(&key), &hash32(&value), &EMPTY_HASH);
+        Node {
+            key,
+            value,
+            hash,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn recompute_hash(&mut self) {
+        self.hash = combine(
+            &link_hash(&self.left),
+            &hash32(&self.key),
+            &hash32(&self.value),
+            &link_hash(&self.right),
+        );
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        match key.cmp(&self.key) {
+            Ordering::Less => self.left.as_deref().and_then(|left| left.get(key)),
+            Ordering::Greater => self.right.as_deref().and_then(|right| right.get(key)),
+            Ordering::Equal => Some(&self.value),
+        }
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        match key.cmp(&self.key) {
+            Ordering::Less => match self.left.as_mut() {
+                Some(left) => left.put(key, value),
+                None => self.left = Some(Box::new(Node::new(key, value))),
+            },
+            Ordering::Greater => match self.right.as_mut() {
+                Some(right) => right.put(key, value),
+                None => self.right = Some(Box::new(Node::new(key, value))),
+            },
+            Ordering::Equal => self.value = value,
+        }
+        self.recompute_hash();
+    }
+
+    fn build_proof(&self, key: &K, steps: &mut Vec<ProofStep>) -> Option<Proof> {
+        match key.cmp(&self.key) {
+            Ordering::Equal => Some(Proof {
+                target_left_hash: link_hash(&self.left),
+                target_right_hash: link_hash(&self.right),
+                steps: steps.clone(),
+            }),
+            Ordering::Less => {
+                let left = self.left.as_deref()?;
+                steps.push(ProofStep {
+                    went_left: true,
+                    sibling_hash: link_hash(&self.right),
+                    key_hash: hash32(&self.key),
+                    value_hash: hash32(&self.value),
+                });
+                let proof = left.build_proof(key, steps);
+                steps.pop();
+                proof
+            }
+            Ordering::Greater => {
+                let right = self.right.as_deref()?;
+                steps.push(ProofStep {
+                    went_left: false,
+                    sibling_hash: link_hash(&self.left),
+                    key_hash: hash32(&self.key),
+                    value_hash: hash32(&self.value),
+                });
+                let proof = right.build_proof(key, steps);
+                steps.pop();
+                proof
+            }
+        }
+    }
+}
+
+// Remove `key` from the subtree rooted at `node`, splicing the removed
+// node's in-order successor into its place the same way
+// `BinaryTreeNode::delete` does, but recomputing cached hashes on the
+// way back up so `hash` stays consistent with the new shape of the tree.
+fn delete<K: Ord + Hash, V: Hash>(mut node: Box<Node<K, V>>, key: &K) -> (Link<K, V>, Option<V>) {
+    match key.cmp(&node.key) {
+        Ordering::Less => match node.left.take() {
+            Some(left) => {
+                let (new_left, removed) = delete(left, key);
+                node.left = new_left;
+                node.recompute_hash();
+                (Some(node), removed)
+            }
+            None => (Some(node), None),
+        },
+        Ordering::Greater => match node.right.take() {
+            Some(right) => {
+                let (new_right, removed) = delete(right, key);
+                node.right = new_right;
+                node.recompute_hash();
+                (Some(node), removed)
+            }
+            None => (Some(node), None),
+        },
+        Ordering::Equal => {
+            let replacement = match (node.left.take(), node.right.take()) {
+                (None, None) => None,
+                (Some(left), None) => Some(left),
+                (None, Some(right)) => Some(right),
+                (Some(left), Some(right)) => {
+                    let (mut successor, remaining_right) = take_leftmost(right);
+                    successor.left = Some(left);
+                    successor.right = remaining_right;
+                    successor.recompute_hash();
+                    Some(successor)
+                }
+            };
+            (replacement, Some(node.value))
+        }
+    }
+}
+
+fn take_leftmost<K: Ord + Hash, V: Hash>(mut node: Box<Node<K, V>>) -> (Box<Node<K, V>>, Link<K, V>) {
+    match node.left.take() {
+        None => {
+            let right = node.right.take();
+            (node, right)
+        }
+        Some(left) => {
+            let (leftmost, remaining_left) = take_leftmost(left);
+            node.left = remaining_left;
+            node.recompute_hash();
+            (leftmost, Some(node))
+        }
+    }
+}
+
+/// One step along a root-to-node path: which way the path went, the
+/// hash of the subtree *not* taken, and the hashes of the key/value at
+/// this ancestor, so [`verify`] can re-derive the ancestor's own hash
+/// without needing its actual key or value.
+#[derive(Clone)]
+pub struct ProofStep {
+    went_left: bool,
+    sibling_hash: [u8; 32],
+    key_hash: [u8; 32],
+    value_hash: [u8; 32],
+}
+
+/// A membership proof produced by [`MerkleTree::prove`]: the hashes of
+/// the proven node's own children, plus the sibling hashes along the
+/// path back to the root, in root-to-node order.
+#[derive(Clone)]
+pub struct Proof {
+    target_left_hash: [u8; 32],
+    target_right_hash: [u8; 32],
+    steps: Vec<ProofStep>,
+}
+
+/// Check that `(key, value)` is a member of the tree whose root hash is
+/// `root_hash`, given a [`Proof`] obtained from [`MerkleTree::prove`].
+pub fn verify<K: Hash, V: Hash>(root_hash: [u8; 32], key: &K, value: &V, proof: &Proof) -> bool {
+    let mut current = combine(
+        &proof.target_left_hash,
+        &hash32(key),
+        &hash32(value),
+        &proof.target_right_hash,
+    );
+    for step in proof.steps.iter().rev() {
+        current = if step.went_left {
+            combine(&current, &step.key_hash, &step.value_hash, &step.sibling_hash)
+        } else {
+            combine(&step.sibling_hash, &step.key_hash, &step.value_hash, &current)
+        };
+    }
+    current == root_hash
+}
+
+/// An authenticated binary search tree: every node caches a hash of its
+/// subtree, derived from its key, value, and the hashes of its
+/// children, so a [`root_hash`](MerkleTree::root_hash) published by the
+/// holder of the tree lets any caller verify a lookup against a
+/// [`Proof`] without trusting whoever served it.
+pub struct MerkleTree<K, V> {
+    root: Link<K, V>,
+}
+
+impl<K: Ord + Hash, V: Hash> MerkleTree<K, V> {
+    pub fn new() -> MerkleTree<K, V> {
+        MerkleTree { root: None }
+    }
+
+    /// Hash of the whole tree. Two `MerkleTree`s with the same entries
+    /// and the same shape always agree on this value.
+    pub fn root_hash(&self) -> [u8; 32] {
+        link_hash(&self.root)
+    }
+
+    /// Build a membership proof for `key`, or `None` if it isn't present.
+    pub fn prove(&self, key: &K) -> Option<Proof> {
+        let mut steps = Vec::new();
+        self.root.as_deref()?.build_proof(key, &mut steps)
+    }
+}
+
+impl<K: Ord + Hash, V: Hash> Default for MerkleTree<K, V> {
+    fn default() -> Self {
+        MerkleTree::new()
+    }
+}
+
+impl<K: Ord + Hash, V: Hash> Tree<K, V> for MerkleTree<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        self.root.as_deref().and_then(|root| root.get(key))
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        match self.root.as_mut() {
+            Some(root) => root.put(key, value),
+            None => self.root = Some(Box::new(Node::new(key, value))),
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        match self.root.take() {
+            None => None,
+            Some(root) => {
+                let (new_root, removed) = delete(root, key);
+                self.root = new_root;
+                removed
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn clear(&mut self) {
+        self.root = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify, MerkleTree};
+    use crate::Tree;
+
+    #[test]
+    fn root_hash_changes_with_contents() {
+        let mut tree: MerkleTree<String, String> = MerkleTree::new();
+        let empty_hash = tree.root_hash();
+
+        tree.put("a".to_string(), "1".to_string());
+        assert_ne!(empty_hash, tree.root_hash());
+    }
+
+    #[test]
+    fn prove_and_verify_membership() {
+        let mut tree: MerkleTree<i32, &str> = MerkleTree::new();
+        tree.put(5, "five");
+        tree.put(2, "two");
+        tree.put(8, "eight");
+        tree.put(1, "one");
+
+        let root_hash = tree.root_hash();
+        let proof = tree.prove(&2).expect("key should be present");
+
+        assert!(verify(root_hash, &2, &"two", &proof));
+        assert!(!verify(root_hash, &2, &"wrong", &proof));
+        assert!(!verify(root_hash, &3, &"two", &proof));
+    }
+
+    #[test]
+    fn prove_missing_key_is_none() {
+        let mut tree: MerkleTree<i32, i32> = MerkleTree::new();
+        tree.put(1, 10);
+        assert!(tree.prove(&2).is_none());
+    }
+
+    #[test]
+    fn proof_is_invalidated_by_later_mutation() {
+        let mut tree: MerkleTree<i32, i32> = MerkleTree::new();
+        tree.put(5, 50);
+        tree.put(2, 20);
+        tree.put(8, 80);
+
+        let proof = tree.prove(&2).unwrap();
+        let root_hash_before = tree.root_hash();
+        assert!(verify(root_hash_before, &2, &20, &proof));
+
+        tree.put(9, 90);
+        assert_ne!(root_hash_before, tree.root_hash());
+        assert!(!verify(tree.root_hash(), &2, &20, &proof));
+    }
+
+    #[test]
+    fn remove_updates_root_hash() {
+        let mut tree: MerkleTree<i32, i32> = MerkleTree::new();
+        tree.put(5, 50);
+        tree.put(2, 20);
+        tree.put(8, 80);
+
+        let before = tree.root_hash();
+        assert_eq!(Some(20), tree.remove(&2));
+        assert_ne!(before, tree.root_hash());
+        assert_eq!(None, tree.get(&2));
+    }
+}
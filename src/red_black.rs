@@ -0,0 +1,321 @@
+use std::cmp::Ordering;
+
+use crate::Tree;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Color {
+    Red,
+    Black,
+}
+
+fn flip(color: Color) -> Color {
+    match color {
+        Color::Red => Color::Black,
+        Color::Black => Color::Red,
+    }
+}
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    color: Color,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+impl<K, V> Node<K, V> {
+    fn new(key: K, value: V) -> Node<K, V> {
+        Node {
+            key,
+            value,
+            color: Color::Red,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+fn is_red<K, V>(link: &Link<K, V>) -> bool {
+    match link {
+        Some(node) => node.color == Color::Red,
+        None => false,
+    }
+}
+
+fn rotate_left<K, V>(mut h: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut x = h.right.take().expect("rotate_left requires a red right child");
+    h.right = x.left.take();
+    x.color = h.color;
+    h.color = Color::Red;
+    x.left = Some(h);
+    x
+}
+
+fn rotate_right<K, V>(mut h: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut x = h.left.take().expect("rotate_right requires a red left child");
+    h.left = x.right.take();
+    x.color = h.color;
+    h.color = Color::Red;
+    x.right = Some(h);
+    x
+}
+
+fn flip_colors<K, V>(h: &mut Box<Node<K, V>>) {
+    h.color = flip(h.color);
+    if let Some(ref mut left) = h.left {
+        left.color = flip(left.color);
+    }
+    if let Some(ref mut right) = h.right {
+        right.color = flip(right.color);
+    }
+}
+
+// Restore the left-leaning red-black invariants on the way back up the
+// insertion/deletion path: lean any right-leaning red link left, resolve
+// two reds in a row with a rotation, and push a red pair up the tree by
+// flipping colors.
+fn fix_up<K, V>(mut h: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    if is_red(&h.right) && !is_red(&h.left) {
+        h = rotate_left(h);
+    }
+    if is_red(&h.left) && is_red(&h.left.as_ref().unwrap().left) {
+        h = rotate_right(h);
+    }
+    if is_red(&h.left) && is_red(&h.right) {
+        flip_colors(&mut h);
+    }
+    h
+}
+
+fn insert<K: Ord, V>(h: Link<K, V>, key: K, value: V) -> Box<Node<K, V>> {
+    let mut h = match h {
+        None => return Box::new(Node::new(key, value)),
+        Some(node) => node,
+    };
+    match key.cmp(&h.key) {
+        Ordering::Less => h.left = Some(insert(h.left.take(), key, value)),
+        Ordering::Greater => h.right = Some(insert(h.right.take(), key, value)),
+        Ordering::Equal => h.value = value,
+    }
+    fix_up(h)
+}
+
+fn get<'a, K: Ord, V>(h: &'a Link<K, V>, key: &K) -> Option<&'a V> {
+    match h {
+        None => None,
+        Some(node) => match key.cmp(&node.key) {
+            Ordering::Less => get(&node.left, key),
+            Ordering::Greater => get(&node.right, key),
+            Ordering::Equal => Some(&node.value),
+        },
+    }
+}
+
+// Push a red link down the left spine so a node can safely be removed
+// from the left subtree without leaving it short a black link.
+fn move_red_left<K, V>(mut h: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    flip_colors(&mut h);
+    if is_red(&h.right.as_ref().unwrap().left) {
+        let right = h.right.take().unwrap();
+        h.right = Some(rotate_right(right));
+        h = rotate_left(h);
+        flip_colors(&mut h);
+    }
+    h
+}
+
+// Symmetric counterpart of `move_red_left`, used before descending right.
+fn move_red_right<K, V>(mut h: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    flip_colors(&mut h);
+    if is_red(&h.left.as_ref().unwrap().left) {
+        h = rotate_right(h);
+        flip_colors(&mut h);
+    }
+    h
+}
+
+fn delete_min<K, V>(mut h: Box<Node<K, V>>) -> (Link<K, V>, K, V) {
+    if h.left.is_none() {
+        let Node { key, value, .. } = *h;
+        return (None, key, value);
+    }
+    if !is_red(&h.left) && !is_red(&h.left.as_ref().unwrap().left) {
+        h = move_red_left(h);
+    }
+    let (new_left, key, value) = delete_min(h.left.take().unwrap());
+    h.left = new_left;
+    (Some(fix_up(h)), key, value)
+}
+
+fn delete_opt<K: Ord, V>(h: Link<K, V>, key: &K) -> (Link<K, V>, Option<V>) {
+    match h {
+        None => (None, None),
+        Some(node) => delete(node, key),
+    }
+}
+
+fn delete<K: Ord, V>(mut h: Box<Node<K, V>>, key: &K) -> (Link<K, V>, Option<V>) {
+    let mut removed = None;
+    if *key < h.key {
+        if h.left.is_some() {
+            if !is_red(&h.left) && !is_red(&h.left.as_ref().unwrap().left) {
+                h = move_red_left(h);
+            }
+            let (new_left, r) = delete_opt(h.left.take(), key);
+            h.left = new_left;
+            removed = r;
+        }
+    } else {
+        if is_red(&h.left) {
+            h = rotate_right(h);
+        }
+        if *key == h.key && h.right.is_none() {
+            let Node { value, .. } = *h;
+            return (None, Some(value));
+        }
+        if h.right.is_some() {
+            if !is_red(&h.right) && !is_red(&h.right.as_ref().unwrap().left) {
+                h = move_red_right(h);
+            }
+            if *key == h.key {
+                let (new_right, min_key, min_value) = delete_min(h.right.take().unwrap());
+                removed = Some(std::mem::replace(&mut h.value, min_value));
+                h.key = min_key;
+                h.right = new_right;
+            } else {
+                let (new_right, r) = delete_opt(h.right.take(), key);
+                h.right = new_right;
+                removed = r;
+            }
+        }
+    }
+    (Some(fix_up(h)), removed)
+}
+
+fn height<K, V>(h: &Link<K, V>) -> usize {
+    match h {
+        None => 0,
+        Some(node) => 1 + height(&node.left).max(height(&node.right)),
+    }
+}
+
+/// A self-balancing binary search tree maintaining the left-leaning
+/// red-black invariants: the root is black, red nodes have only black
+/// children, and every root-to-leaf path passes through the same number
+/// of black nodes. Unlike [`BinaryTree`](crate::BinaryTree), this keeps
+/// `get`/`put`/`remove` at `O(log n)` even when keys arrive in sorted
+/// order.
+pub struct RedBlackTree<K, V> {
+    root: Link<K, V>,
+}
+
+impl<K: Ord, V> RedBlackTree<K, V> {
+    pub fn new() -> RedBlackTree<K, V> {
+        RedBlackTree { root: None }
+    }
+
+    /// Height of the tree, i.e. the number of nodes on the longest
+    /// root-to-leaf path. `0` for an empty tree.
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+}
+
+impl<K: Ord, V> Default for RedBlackTree<K, V> {
+    fn default() -> Self {
+        RedBlackTree::new()
+    }
+}
+
+impl<K: Ord, V> Tree<K, V> for RedBlackTree<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        get(&self.root, key)
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        let mut root = insert(self.root.take(), key, value);
+        root.color = Color::Black;
+        self.root = Some(root);
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let (new_root, removed) = delete_opt(self.root.take(), key);
+        self.root = new_root.map(|mut node| {
+            node.color = Color::Black;
+            node
+        });
+        removed
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn clear(&mut self) {
+        self.root = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedBlackTree;
+    use crate::Tree;
+
+    #[test]
+    fn basic_put_get() {
+        let mut tree: RedBlackTree<String, String> = RedBlackTree::new();
+        tree.put("2".to_string(), "goodbye".to_string());
+        tree.put("1".to_string(), "hello".to_string());
+        tree.put("3".to_string(), "cherry".to_string());
+
+        assert_eq!(&"hello".to_string(), tree.get(&"1".to_string()).unwrap());
+        assert_eq!(&"goodbye".to_string(), tree.get(&"2".to_string()).unwrap());
+        assert_eq!(&"cherry".to_string(), tree.get(&"3".to_string()).unwrap());
+    }
+
+    #[test]
+    fn put_get_remove() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for i in 0..10 {
+            tree.put(i, i * 10);
+        }
+        for i in 0..10 {
+            assert_eq!(Some(i * 10), tree.remove(&i));
+            assert_eq!(None, tree.get(&i));
+        }
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn stays_balanced_on_ascending_inserts() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for i in 0..1000 {
+            tree.put(i, i);
+        }
+        for i in 0..1000 {
+            assert_eq!(Some(i), tree.get(&i).copied());
+        }
+
+        // A balanced tree of 1000 nodes has height around log2(1000) ~ 10;
+        // an unbalanced BST degrading to a linked list would have height
+        // 1000. Left-leaning red-black trees guarantee height <= 2*log2(n+1).
+        let bound = 2 * (1000f64 + 1.0).log2().ceil() as usize;
+        assert!(
+            tree.height() <= bound,
+            "expected height <= {}, got {}",
+            bound,
+            tree.height()
+        );
+    }
+
+    #[test]
+    fn clear_empties_tree() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.put(1, 1);
+        tree.clear();
+        assert!(tree.is_empty());
+        assert_eq!(None, tree.get(&1));
+    }
+}